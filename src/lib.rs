@@ -1,8 +1,8 @@
 /// The main crate for lodestone-inside
 ///
 /// ## Overview
-/// 
-/// Determines if a given FeaturePoint is inside a given FeaturePolygon. This 
+///
+/// Determines if a given FeaturePoint is inside a given FeaturePolygon. This
 /// takes into account holes in the FeaturePolygon.
 /// Inspired by [turf-inside](https://github.com/Turfjs/turf-inside).
 
@@ -12,31 +12,107 @@ extern crate lodestone_polygon;
 use lodestone_point::FeaturePoint;
 use lodestone_polygon::FeaturePolygon;
 
+/// A numeric coordinate scalar usable by the containment routines.
+///
+/// Implemented for `f32` and `f64` so the same algorithms run over either
+/// precision instead of being hardcoded to one float type, and so the
+/// boundary check in `on_ring_boundary` can compare against a tolerance
+/// instead of bitwise equality.
+pub trait Coordinate: Copy + PartialOrd +
+    std::ops::Add<Output = Self> +
+    std::ops::Sub<Output = Self> +
+    std::ops::Mul<Output = Self> +
+    std::ops::Div<Output = Self> {
+
+  /// The additive identity
+  fn zero() -> Self;
+
+  /// Absolute value
+  fn abs(self) -> Self;
+
+  /// Square root, needed to turn the `relative_pos` cross product into an
+  /// actual point-to-line distance for the boundary tolerance check
+  fn sqrt(self) -> Self;
+
+  /// The distance tolerance used by `on_ring_boundary` to decide a point
+  /// lies on an edge
+  fn boundary_epsilon() -> Self;
+
+  /// The tolerance used by `ray_intersects_segment` to detect a ray passing
+  /// through (or within a hair of) an edge endpoint, distinct from
+  /// `boundary_epsilon()` since the two guard unrelated degenerate cases at
+  /// different magnitudes
+  fn raycast_nudge() -> Self;
+
+  /// A sentinel slope standing in for a division by a zero run (a vertical
+  /// edge, or a point directly above or below the edge's lower endpoint)
+  fn vertical_slope() -> Self;
+}
+
+impl Coordinate for f64 {
+  fn zero() -> Self { 0.0 }
+  fn abs(self) -> Self { self.abs() }
+  fn sqrt(self) -> Self { self.sqrt() }
+  fn boundary_epsilon() -> Self { 1e-9 }
+  fn raycast_nudge() -> Self { 1e-5 }
+  fn vertical_slope() -> Self { 1e10 }
+}
+
+impl Coordinate for f32 {
+  fn zero() -> Self { 0.0 }
+  fn abs(self) -> Self { self.abs() }
+  fn sqrt(self) -> Self { self.sqrt() }
+  fn boundary_epsilon() -> Self { 1e-5 }
+  fn raycast_nudge() -> Self { 1e-3 }
+  fn vertical_slope() -> Self { 1e7 }
+}
+
+/// The three-way classification of a point's position relative to a polygon
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CoordPos {
+  Inside,
+  Outside,
+  OnBoundary,
+}
+
 pub trait Inside {
   fn inside(&self, poly: &FeaturePolygon) -> bool;
+  fn coordinate_position(&self, poly: &FeaturePolygon) -> CoordPos;
 }
 
 impl Inside for FeaturePoint {
   fn inside(&self, poly: &FeaturePolygon) -> bool {
     inside(&self, &poly)
   }
+
+  fn coordinate_position(&self, poly: &FeaturePolygon) -> CoordPos {
+    coordinate_position(&self, &poly)
+  }
 }
 
 pub fn inside(
     pt: &FeaturePoint,
     poly: &FeaturePolygon) -> bool {
 
-  let pt_coords = pt.coordinates();
-  let poly_coords = poly.coordinates();
-  
+  inside_coords(&pt.coordinates(), &poly.coordinates())
+}
+
+/// Generic entry point for `inside` that works directly off coordinate
+/// vectors instead of a `FeaturePoint`/`FeaturePolygon`, so callers whose
+/// coordinates aren't `f64` (e.g. `f32` data from an embedded or graphics
+/// pipeline) can run the containment test without going through those types.
+pub fn inside_coords<T: Coordinate>(
+    pt: &Vec<T>,
+    poly_coords: &Vec<Vec<Vec<T>>>) -> bool {
+
   // determine if the point is inside the shell
   let mut iter = poly_coords.iter();
-  let mut inside_poly = in_ring(&pt_coords, iter.next().unwrap());
+  let mut inside_poly = in_ring(&pt, iter.next().unwrap());
 
   // if inside the shell check the holes
   if inside_poly {
     for hole in iter {
-      if in_ring(&pt_coords, &hole) {
+      if in_ring(&pt, &hole) {
         inside_poly = false;
         break;
       }
@@ -46,10 +122,251 @@ pub fn inside(
   inside_poly
 }
 
+/// Ray-casting (even-odd) alternative to the winding-number `inside`, for
+/// callers who want the classic crossing test and its well-known degenerate-case
+/// handling instead.
+pub fn inside_raycast(
+    pt: &FeaturePoint,
+    poly: &FeaturePolygon) -> bool {
+
+  inside_raycast_coords(&pt.coordinates(), &poly.coordinates())
+}
+
+/// Generic entry point for `inside_raycast` that works directly off
+/// coordinate vectors instead of a `FeaturePoint`/`FeaturePolygon`, so
+/// callers whose coordinates aren't `f64` can run the ray-casting test
+/// without going through those types.
+pub fn inside_raycast_coords<T: Coordinate>(
+    pt: &Vec<T>,
+    poly_coords: &Vec<Vec<Vec<T>>>) -> bool {
+
+  // determine if the point is inside the shell
+  let mut iter = poly_coords.iter();
+  let mut inside_poly = raycast_in_ring(&pt, iter.next().unwrap());
+
+  // if inside the shell check the holes
+  if inside_poly {
+    for hole in iter {
+      if raycast_in_ring(&pt, &hole) {
+        inside_poly = false;
+        break;
+      }
+    }
+  }
+
+  inside_poly
+}
+
+/// Algorithm: even-odd rule via [ray casting](https://en.wikipedia.org/wiki/Point_in_polygon#Ray_casting_algorithm)
+fn raycast_in_ring<T: Coordinate>(
+    pt: &Vec<T>,
+    ring: &Vec<Vec<T>>) -> bool {
+
+  let mut crossings = 0;
+
+  for edge in ring.windows(2) {
+    let edge = edge.to_vec();
+
+    if ray_intersects_segment(&pt, &edge) { crossings += 1; }
+  }
+
+  crossings % 2 != 0 // odd means the point is inside the ring
+}
+
+/// Returns true if a rightward ray cast from `pt` crosses `edge`
+fn ray_intersects_segment<T: Coordinate>(
+    pt: &Vec<T>,
+    edge: &Vec<Vec<T>>) -> bool {
+
+  // orient the edge so its lower endpoint is a and upper is b
+  let (a, b) = if edge[0][1] <= edge[1][1] {
+    (edge[0].clone(), edge[1].clone())
+  } else {
+    (edge[1].clone(), edge[0].clone())
+  };
+
+  let mut pt = pt.to_vec();
+
+  // nudge the point off an endpoint's y to avoid a vertex double-count
+  if (pt[1] - a[1]).abs() <= T::raycast_nudge() || (pt[1] - b[1]).abs() <= T::raycast_nudge() {
+    pt[1] = pt[1] + T::raycast_nudge();
+  }
+
+  if pt[1] > b[1] || pt[1] < a[1] || pt[0] > max(a[0], b[0]) {
+    return false;
+  }
+
+  if pt[0] < min(a[0], b[0]) {
+    return true;
+  }
+
+  let m_red = if b[0] != a[0] { (b[1] - a[1]) / (b[0] - a[0]) } else { T::vertical_slope() };
+  let m_blue = if pt[0] != a[0] { (pt[1] - a[1]) / (pt[0] - a[0]) } else { T::vertical_slope() };
+
+  m_blue >= m_red
+}
+
+/// Tests `pts` against `poly`, reusing the shell's bounding box to skip the
+/// full winding-number test for points that obviously fall outside it. Holes
+/// are only checked for points that pass the shell test, since a point
+/// outside the shell cannot be inside a hole either.
+pub fn inside_many(
+    pts: &[FeaturePoint],
+    poly: &FeaturePolygon) -> Vec<bool> {
+
+  let poly_coords = poly.coordinates();
+  let mut iter = poly_coords.iter();
+  let shell = iter.next().unwrap();
+  let holes: Vec<&Vec<Vec<f64>>> = iter.collect();
+  let shell_bbox = bbox(shell);
+
+  pts.iter().map(|pt| {
+    let pt_coords = pt.coordinates();
+
+    if !shell_bbox.contains(&pt_coords) || !in_ring(&pt_coords, shell) {
+      return false;
+    }
+
+    !holes.iter().any(|hole| in_ring(&pt_coords, hole))
+  }).collect()
+}
+
+/// An axis-aligned bounding box over a ring's coordinates
+struct BBox<T: Coordinate> {
+  min_x: T,
+  max_x: T,
+  min_y: T,
+  max_y: T,
+}
+
+impl<T: Coordinate> BBox<T> {
+  /// Returns true if `pt` falls within the box (inclusive)
+  fn contains(&self, pt: &Vec<T>) -> bool {
+    pt[0] >= self.min_x && pt[0] <= self.max_x && pt[1] >= self.min_y && pt[1] <= self.max_y
+  }
+}
+
+/// Computes the bounding box over a ring's coordinates
+fn bbox<T: Coordinate>(ring: &Vec<Vec<T>>) -> BBox<T> {
+  let mut min_x = ring[0][0];
+  let mut max_x = ring[0][0];
+  let mut min_y = ring[0][1];
+  let mut max_y = ring[0][1];
+
+  for coord in ring.iter() {
+    if coord[0] < min_x { min_x = coord[0]; }
+    if coord[0] > max_x { max_x = coord[0]; }
+    if coord[1] < min_y { min_y = coord[1]; }
+    if coord[1] > max_y { max_y = coord[1]; }
+  }
+
+  BBox { min_x, max_x, min_y, max_y }
+}
+
+/// Tests `pt` against each polygon in `polys` in order and returns the index
+/// of the first one that contains it, or `None` if it falls outside all of
+/// them. Useful for GeoJSON `MultiPolygon` geometries and layer-wide lookups
+/// where the caller needs to know which feature matched, not just whether one did.
+pub fn inside_multi(
+    pt: &FeaturePoint,
+    polys: &[FeaturePolygon]) -> Option<usize> {
+
+  polys.iter().position(|poly| inside(&pt, &poly))
+}
+
+/// Classifies `pt` as `Inside`, `Outside`, or `OnBoundary` with respect to `poly`.
+///
+/// A point is `OnBoundary` if it lies within `T::boundary_epsilon()` of an
+/// edge (including its endpoints) of the shell or any hole, checked before
+/// falling back to the winding-number test already used by `inside`.
+pub fn coordinate_position(
+    pt: &FeaturePoint,
+    poly: &FeaturePolygon) -> CoordPos {
+
+  coordinate_position_coords(&pt.coordinates(), &poly.coordinates())
+}
+
+/// Generic entry point for `coordinate_position` that works directly off
+/// coordinate vectors instead of a `FeaturePoint`/`FeaturePolygon`, so
+/// callers whose coordinates aren't `f64` can classify a point without
+/// going through those types.
+pub fn coordinate_position_coords<T: Coordinate>(
+    pt: &Vec<T>,
+    poly_coords: &Vec<Vec<Vec<T>>>) -> CoordPos {
+
+  for ring in poly_coords.iter() {
+    if on_ring_boundary(&pt, ring) {
+      return CoordPos::OnBoundary;
+    }
+  }
+
+  if inside_coords(&pt, &poly_coords) {
+    CoordPos::Inside
+  } else {
+    CoordPos::Outside
+  }
+}
+
+/// Returns true if `pt` lies within `T::boundary_epsilon()` of one of the
+/// ring's edges (inclusive of endpoints)
+fn on_ring_boundary<T: Coordinate>(
+    pt: &Vec<T>,
+    ring: &Vec<Vec<T>>) -> bool {
+
+  for edge in ring.windows(2) {
+    let edge = edge.to_vec();
+
+    if distance_to_segment(&pt, &edge) <= T::boundary_epsilon() && on_segment_bounds(&pt, &edge) {
+      return true;
+    }
+  }
+
+  false
+}
+
+/// The perpendicular distance from `pt` to the infinite line through `edge`.
+///
+/// `relative_pos` returns a cross product (edge length times perpendicular
+/// distance), not a distance on its own, so it's divided by the edge length
+/// here to give `on_ring_boundary` a tolerance that means the same thing
+/// regardless of how long or short the edge is.
+fn distance_to_segment<T: Coordinate>(
+    pt: &Vec<T>,
+    edge: &Vec<Vec<T>>) -> T {
+
+  let (x1, y1, x2, y2) = (edge[0][0], edge[0][1], edge[1][0], edge[1][1]);
+  let (dx, dy) = (x2 - x1, y2 - y1);
+  let edge_len = (dx * dx + dy * dy).sqrt();
+
+  if edge_len <= T::zero() {
+    // degenerate (zero-length) edge: fall back to distance to its one point
+    let (ex, ey) = (pt[0] - x1, pt[1] - y1);
+    return (ex * ex + ey * ey).sqrt();
+  }
+
+  relative_pos(&pt, &edge).abs() / edge_len
+}
+
+/// Returns true if `pt` falls within the edge's x/y bounding interval
+/// (inclusive), widened by `T::boundary_epsilon()` on every side so that a
+/// point within tolerance of a horizontal or vertical edge still passes
+fn on_segment_bounds<T: Coordinate>(
+    pt: &Vec<T>,
+    edge: &Vec<Vec<T>>) -> bool {
+
+  let (x1, y1, x2, y2) = (edge[0][0], edge[0][1], edge[1][0], edge[1][1]);
+  let eps = T::boundary_epsilon();
+
+  let (min_x, max_x) = if x1 <= x2 { (x1 - eps, x2 + eps) } else { (x2 - eps, x1 + eps) };
+  let (min_y, max_y) = if y1 <= y2 { (y1 - eps, y2 + eps) } else { (y2 - eps, y1 + eps) };
+
+  pt[0] >= min_x && pt[0] <= max_x && pt[1] >= min_y && pt[1] <= max_y
+}
+
 /// Algorithm: [Winding Number](http://geomalgorithms.com/a03-_inclusion.html)
-fn in_ring(
-    pt: &Vec<f64>, 
-    ring: &Vec<Vec<f64>>) -> bool {
+fn in_ring<T: Coordinate>(
+    pt: &Vec<T>,
+    ring: &Vec<Vec<T>>) -> bool {
 
   let mut wn = 0; // the winding number counter
 
@@ -74,19 +391,19 @@ fn in_ring(
 }
 
 /// Returns true if the point is left of a directed line
-fn is_left(
-    pt: &Vec<f64>,
-    line: &Vec<Vec<f64>>) -> bool {
+fn is_left<T: Coordinate>(
+    pt: &Vec<T>,
+    line: &Vec<Vec<T>>) -> bool {
 
-  relative_pos(&pt, &line) > 0.0
+  relative_pos(&pt, &line) > T::zero()
 }
 
 /// Returns true if the point is right of a directed line
-fn is_right(
-    pt: &Vec<f64>,
-    line: &Vec<Vec<f64>>) -> bool {
+fn is_right<T: Coordinate>(
+    pt: &Vec<T>,
+    line: &Vec<Vec<T>>) -> bool {
 
-  relative_pos(&pt, &line) < 0.0
+  relative_pos(&pt, &line) < T::zero()
 }
 
 /// Determines if a point is Left|On|Right of a directed line.
@@ -95,20 +412,30 @@ fn is_right(
 /// > 0 for `pt` left of the `line`
 /// = 0 for `pt` on the `line`
 /// < 0 for `pt` right of the `line`
-fn relative_pos(
-    pt: &Vec<f64>,
-    line: &Vec<Vec<f64>>) -> f64 {
+fn relative_pos<T: Coordinate>(
+    pt: &Vec<T>,
+    line: &Vec<Vec<T>>) -> T {
 
   let (x1, y1, x2, y2) = (line[0][0], line[0][1], line[1][0], line[1][1]);
-  
+
   (x2 - x1) * (pt[1] - y1) - (y2 - y1) * (pt[0] -  x1)
 }
 
+/// Returns the larger of two coordinate values
+fn max<T: Coordinate>(a: T, b: T) -> T {
+  if a >= b { a } else { b }
+}
+
+/// Returns the smaller of two coordinate values
+fn min<T: Coordinate>(a: T, b: T) -> T {
+  if a <= b { a } else { b }
+}
+
 #[cfg(test)]
 mod tests {
   use lodestone_point::FeaturePoint;
   use lodestone_polygon::FeaturePolygon;
-  use super::{inside, in_ring, is_left, is_right, relative_pos};
+  use super::{inside, inside_coords, inside_multi, inside_many, inside_raycast, inside_raycast_coords, ray_intersects_segment, coordinate_position, coordinate_position_coords, in_ring, is_left, is_right, relative_pos, CoordPos};
 
   #[test]
   fn test_inside_simple() {
@@ -131,6 +458,144 @@ mod tests {
     assert_eq!(inside(&pt2, &poly), false);
   }
 
+  #[test]
+  fn test_inside_multi() {
+    let poly1 = FeaturePolygon::new(vec![vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]]]);
+    let poly2 = FeaturePolygon::new(vec![vec![vec![4.0, 4.0], vec![6.0, 4.0], vec![6.0, 6.0], vec![4.0, 6.0], vec![4.0, 4.0]]]);
+    let polys = vec![poly1, poly2];
+
+    let pt_in_first = FeaturePoint::new(vec![1.0, 1.0]);
+    let pt_in_second = FeaturePoint::new(vec![5.0, 5.0]);
+    let pt_in_neither = FeaturePoint::new(vec![10.0, 10.0]);
+
+    assert_eq!(inside_multi(&pt_in_first, &polys), Some(0));
+    assert_eq!(inside_multi(&pt_in_second, &polys), Some(1));
+    assert_eq!(inside_multi(&pt_in_neither, &polys), None);
+  }
+
+  #[test]
+  fn test_inside_raycast_simple() {
+    let outer = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]];
+    let poly = FeaturePolygon::new(vec![outer]);
+    let pt1 = FeaturePoint::new(vec![1.0, 1.0]);
+    let pt2 = FeaturePoint::new(vec![3.0, 3.0]);
+
+    assert_eq!(inside_raycast(&pt1, &poly), true);
+    assert_eq!(inside_raycast(&pt2, &poly), false);
+  }
+
+  #[test]
+  fn test_inside_raycast_concave_hole() {
+    let outer = vec![vec![-1.0, -1.0], vec![3.0, 3.0], vec![2.0, 0.0], vec![5.0, -1.0], vec![-1.0, -1.0]];
+    let hole = vec![vec![1.0, 0.0], vec![1.2, 0.5], vec![1.6, 0.5], vec![1.4, 0.0], vec![1.0, 0.0]];
+    let poly = FeaturePolygon::new(vec![outer, hole]);
+    // strictly interior, unlike [0.0, 0.0] which sits exactly on the -1,-1 -> 3,3 edge
+    let pt1 = FeaturePoint::new(vec![1.0, -0.5]);
+    let pt2 = FeaturePoint::new(vec![1.35, 0.3]); // in hole
+
+    assert_eq!(inside_raycast(&pt1, &poly), true);
+    assert_eq!(inside_raycast(&pt2, &poly), false);
+  }
+
+  #[test]
+  fn test_ray_intersects_segment_vertex() {
+    // ray passes exactly through the lower vertex of the edge
+    let edge = vec![vec![0.0, 0.0], vec![0.0, 2.0]];
+    let pt = vec![-1.0, 0.0];
+
+    assert_eq!(ray_intersects_segment(&pt, &edge), true);
+  }
+
+  #[test]
+  fn test_ray_intersects_segment_f32() {
+    // same geometry as test_ray_intersects_segment_vertex, run in f32
+    let edge: Vec<Vec<f32>> = vec![vec![0.0, 0.0], vec![0.0, 2.0]];
+    let pt: Vec<f32> = vec![-1.0, 0.0];
+
+    assert_eq!(ray_intersects_segment(&pt, &edge), true);
+  }
+
+  #[test]
+  fn test_inside_coords_f32() {
+    // same geometry as test_inside_concave_hole, run in f32 through the
+    // coordinate-vector entry points rather than FeaturePoint/FeaturePolygon
+    let outer: Vec<Vec<f32>> = vec![vec![-1.0, -1.0], vec![3.0, 3.0], vec![2.0, 0.0], vec![5.0, -1.0], vec![-1.0, -1.0]];
+    let hole: Vec<Vec<f32>> = vec![vec![1.0, 0.0], vec![1.2, 0.5], vec![1.6, 0.5], vec![1.4, 0.0], vec![1.0, 0.0]];
+    let poly_coords = vec![outer, hole];
+
+    let pt1: Vec<f32> = vec![0.0, 0.0];
+    let pt2: Vec<f32> = vec![1.35, 0.3]; // in hole
+
+    assert_eq!(inside_coords(&pt1, &poly_coords), true);
+    assert_eq!(inside_coords(&pt2, &poly_coords), false);
+  }
+
+  #[test]
+  fn test_inside_raycast_coords_f32() {
+    let outer: Vec<Vec<f32>> = vec![vec![-1.0, -1.0], vec![3.0, 3.0], vec![2.0, 0.0], vec![5.0, -1.0], vec![-1.0, -1.0]];
+    let hole: Vec<Vec<f32>> = vec![vec![1.0, 0.0], vec![1.2, 0.5], vec![1.6, 0.5], vec![1.4, 0.0], vec![1.0, 0.0]];
+    let poly_coords = vec![outer, hole];
+
+    let pt1: Vec<f32> = vec![1.0, -0.5];
+    let pt2: Vec<f32> = vec![1.35, 0.3]; // in hole
+
+    assert_eq!(inside_raycast_coords(&pt1, &poly_coords), true);
+    assert_eq!(inside_raycast_coords(&pt2, &poly_coords), false);
+  }
+
+  #[test]
+  fn test_coordinate_position_coords_f32() {
+    let outer: Vec<Vec<f32>> = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]];
+    let poly_coords = vec![outer];
+
+    let pt_inside: Vec<f32> = vec![1.0, 1.0];
+    let pt_on_edge: Vec<f32> = vec![1.0, 0.0];
+
+    assert_eq!(coordinate_position_coords(&pt_inside, &poly_coords), CoordPos::Inside);
+    assert_eq!(coordinate_position_coords(&pt_on_edge, &poly_coords), CoordPos::OnBoundary);
+  }
+
+  #[test]
+  fn test_inside_many() {
+    let outer = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]];
+    let hole = vec![vec![0.5, 0.5], vec![0.5, 1.0], vec![1.0, 1.0], vec![1.0, 0.5], vec![0.5, 0.5]];
+    let poly = FeaturePolygon::new(vec![outer, hole]);
+
+    let pts = vec![
+      FeaturePoint::new(vec![1.5, 1.5]), // inside shell, outside hole
+      FeaturePoint::new(vec![0.7, 0.7]), // inside hole
+      FeaturePoint::new(vec![10.0, 10.0]), // outside bbox entirely
+    ];
+
+    assert_eq!(inside_many(&pts, &poly), vec![true, false, false]);
+  }
+
+  #[test]
+  fn test_coordinate_position() {
+    let outer = vec![vec![0.0, 0.0], vec![2.0, 0.0], vec![2.0, 2.0], vec![0.0, 2.0], vec![0.0, 0.0]];
+    let poly = FeaturePolygon::new(vec![outer]);
+
+    let pt_inside = FeaturePoint::new(vec![1.0, 1.0]);
+    let pt_outside = FeaturePoint::new(vec![3.0, 3.0]);
+    let pt_on_edge = FeaturePoint::new(vec![1.0, 0.0]);
+    let pt_on_vertex = FeaturePoint::new(vec![0.0, 0.0]);
+
+    assert_eq!(coordinate_position(&pt_inside, &poly), CoordPos::Inside);
+    assert_eq!(coordinate_position(&pt_outside, &poly), CoordPos::Outside);
+    assert_eq!(coordinate_position(&pt_on_edge, &poly), CoordPos::OnBoundary);
+    assert_eq!(coordinate_position(&pt_on_vertex, &poly), CoordPos::OnBoundary);
+  }
+
+  #[test]
+  fn test_coordinate_position_hole_boundary() {
+    let outer = vec![vec![-1.0, -1.0], vec![3.0, 3.0], vec![2.0, 0.0], vec![5.0, -1.0], vec![-1.0, -1.0]];
+    let hole = vec![vec![1.0, 0.0], vec![1.2, 0.5], vec![1.6, 0.5], vec![1.4, 0.0], vec![1.0, 0.0]];
+    let poly = FeaturePolygon::new(vec![outer, hole]);
+    let pt_on_hole_edge = FeaturePoint::new(vec![1.0, 0.0]);
+
+    assert_eq!(coordinate_position(&pt_on_hole_edge, &poly), CoordPos::OnBoundary);
+  }
+
   #[test]
   fn test_in_ring() {
     let pt1 = vec![1.0, 1.0];
@@ -145,7 +610,7 @@ mod tests {
     let ring2 = vec![vec![0.0, 0.0], vec![0.0, 2.0], vec![2.0, 2.0], vec![2.0, 0.0], vec![0.0, 0.0]];
     let ring3 = vec![vec![0.0, 0.0], vec![3.0, 3.0], vec![2.0, 0.0], vec![0.0, 0.0]];
     let ring4 = vec![vec![-1.0, -1.0], vec![3.0, 3.0], vec![2.0, 0.0], vec![5.0, -1.0], vec![-1.0, -1.0]];
-    
+
     assert_eq!(in_ring(&pt1, &ring1), true);
     assert_eq!(in_ring(&pt1, &ring2), true);
     assert_eq!(in_ring(&pt1, &ring3), true);
@@ -160,7 +625,7 @@ mod tests {
     assert_eq!(in_ring(&pt3, &ring2), false);
     assert_eq!(in_ring(&pt3, &ring3), false);
     assert_eq!(in_ring(&pt3, &ring4), false);
-    
+
     assert_eq!(in_ring(&pt4, &ring1), true);
     assert_eq!(in_ring(&pt4, &ring2), true);
     assert_eq!(in_ring(&pt4, &ring3), true);
@@ -170,7 +635,7 @@ mod tests {
     assert_eq!(in_ring(&pt5, &ring2), true);
     assert_eq!(in_ring(&pt5, &ring3), true);
     assert_eq!(in_ring(&pt5, &ring4), true);
-    
+
     assert_eq!(in_ring(&pt6, &ring4), false);
     assert_eq!(in_ring(&pt7, &ring4), true);
   }